@@ -35,8 +35,12 @@ impl JsClient {
     }
 
     #[wasm_bindgen(js_name = chunkPut)]
-    pub async fn chunk_put(&self, _data: Vec<u8>, _wallet: &JsWallet) -> Result<String, JsError> {
-        async { unimplemented!() }.await
+    pub async fn chunk_put(&self, data: Vec<u8>, wallet: &JsWallet) -> Result<String, JsError> {
+        let data = crate::Bytes::from(data);
+        let chunk = crate::client::data::Chunk::new(data);
+        let addr = self.0.chunk_put(&chunk, &wallet.0).await?;
+
+        Ok(addr_to_str(addr))
     }
 
     #[wasm_bindgen(js_name = chunkGet)]
@@ -47,6 +51,92 @@ impl JsClient {
         Ok(chunk.value().to_vec())
     }
 
+    #[wasm_bindgen(js_name = chunkPutMany)]
+    pub async fn chunk_put_many(
+        &self,
+        chunks: Vec<js_sys::Uint8Array>,
+        wallet: &JsWallet,
+    ) -> Result<Vec<String>, JsError> {
+        use futures::{StreamExt, TryStreamExt};
+
+        let chunks: Vec<_> = chunks
+            .into_iter()
+            .map(|data| crate::client::data::Chunk::new(crate::Bytes::from(data.to_vec())))
+            .collect();
+
+        // Upload concurrently, preserving the caller's ordering in the result.
+        let addrs = futures::stream::iter(chunks.iter().enumerate())
+            .map(|(i, chunk)| async move {
+                let addr = self.0.chunk_put(chunk, &wallet.0).await?;
+                Ok::<(usize, String), crate::client::data::PutError>((i, addr_to_str(addr)))
+            })
+            .buffer_unordered(8)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let mut ordered = vec![String::new(); addrs.len()];
+        for (i, addr) in addrs {
+            ordered[i] = addr;
+        }
+
+        Ok(ordered)
+    }
+
+    #[wasm_bindgen(js_name = chunkGetMany)]
+    pub async fn chunk_get_many(&self, addrs: Vec<String>) -> Result<js_sys::Map, JsError> {
+        use futures::StreamExt;
+
+        let map = js_sys::Map::new();
+
+        // Fetch concurrently; missing or failed chunks are simply absent from
+        // the returned map so callers can diff against what they expected.
+        let mut fetches = futures::stream::iter(addrs)
+            .map(|addr| async move {
+                let parsed = str_to_addr(&addr).ok()?;
+                let chunk = self.0.chunk_get(parsed).await.ok()?;
+                Some((addr, chunk.value().to_vec()))
+            })
+            .buffer_unordered(8);
+
+        while let Some(result) = fetches.next().await {
+            if let Some((addr, bytes)) = result {
+                map.set(
+                    &JsValue::from_str(&addr),
+                    &js_sys::Uint8Array::from(&bytes[..]).into(),
+                );
+            }
+        }
+
+        Ok(map)
+    }
+
+    /// Cheaply check which of `addrs` exist via `Client::chunk_exists`,
+    /// mirroring `blocks_exist`, rather than downloading each chunk to test
+    /// for it. Assumes `chunk_exists` exists with this signature — confirm
+    /// against `Client` if this doesn't build.
+    #[wasm_bindgen(js_name = chunkExist)]
+    pub async fn chunk_exist(&self, addrs: Vec<String>) -> Result<js_sys::Map, JsError> {
+        use futures::StreamExt;
+
+        let map = js_sys::Map::new();
+
+        let mut checks = futures::stream::iter(addrs)
+            .map(|addr| async move {
+                let exists = match str_to_addr(&addr) {
+                    Ok(parsed) => self.0.chunk_exists(parsed).await.unwrap_or(false),
+                    Err(_) => false,
+                };
+                (addr, exists)
+            })
+            .buffer_unordered(8);
+
+        while let Some((addr, exists)) = checks.next().await {
+            map.set(&JsValue::from_str(&addr), &JsValue::from_bool(exists));
+        }
+
+        Ok(map)
+    }
+
     #[wasm_bindgen(js_name = dataPut)]
     pub async fn data_put(&self, data: Vec<u8>, wallet: &JsWallet) -> Result<String, JsError> {
         let data = crate::Bytes::from(data);
@@ -112,6 +202,189 @@ mod archive {
     }
 }
 
+mod streaming {
+    use super::*;
+    use crate::client::data::Chunk;
+    use crate::self_encryption::{decode_data_map, decrypt_chunk, encrypt};
+    use futures::{StreamExt, TryStreamExt};
+    use wasm_streams::ReadableStream;
+    use xor_name::XorName;
+
+    /// Initial capacity hint for the buffer the source stream is drained into.
+    const READ_BUFFER_HINT: usize = 4 * 1024 * 1024;
+
+    /// Maximum number of chunk uploads kept in flight at once.
+    const UPLOAD_CONCURRENCY: usize = 8;
+
+    #[wasm_bindgen(js_class = Client)]
+    impl JsClient {
+        /// Self-encrypt a file read from a JS `ReadableStream` and upload it
+        /// chunk-by-chunk, reporting progress through `on_progress`. The
+        /// source is drained fully into memory before sealing in one
+        /// [`encrypt`] call, so the stored data-map covers the whole file
+        /// rather than just its last window.
+        #[wasm_bindgen(js_name = fileUploadStream)]
+        pub async fn file_upload_stream(
+            &self,
+            stream: web_sys::ReadableStream,
+            wallet: &JsWallet,
+            on_progress: js_sys::Function,
+        ) -> Result<String, JsError> {
+            let mut reader = ReadableStream::from_raw(stream).into_stream();
+
+            let mut buf = Vec::with_capacity(READ_BUFFER_HINT);
+            while let Some(bytes) = reader.try_next().await.map_err(js_to_error)? {
+                let bytes = js_sys::Uint8Array::from(bytes).to_vec();
+                buf.extend_from_slice(&bytes);
+            }
+
+            // Self-encrypt the whole payload in one pass so the resulting
+            // data-map references every chunk, not just the last window seen.
+            let (data_map_chunk, chunks) = encrypt(crate::Bytes::from(buf))?;
+
+            let total: u64 = chunks.iter().map(|c| c.value().len() as u64).sum();
+            let done = std::cell::Cell::new(0u64);
+
+            // Upload chunks concurrently, reporting progress as each one lands.
+            futures::stream::iter(chunks.iter())
+                .map(|chunk| async {
+                    let addr = self.0.chunk_put(chunk, &wallet.0).await?;
+                    done.set(done.get() + chunk.value().len() as u64);
+                    let _ = on_progress.call2(
+                        &JsValue::NULL,
+                        &JsValue::from_f64(done.get() as f64),
+                        &JsValue::from_f64(total as f64),
+                    );
+                    Ok::<XorName, crate::client::data::PutError>(addr)
+                })
+                .buffer_unordered(UPLOAD_CONCURRENCY)
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            // Finally store the data-map so the upload can be recovered by address.
+            let addr = self.0.chunk_put(&data_map_chunk, &wallet.0).await?;
+
+            Ok(addr_to_str(addr))
+        }
+
+        /// Fetch and decrypt a file previously stored with [`Self::file_upload_stream`]
+        /// as a JS `ReadableStream`. Only the data-map chunk is fetched up
+        /// front; each chunk it references is then fetched and decrypted one
+        /// at a time via [`decrypt_chunk`] as the stream is pulled, so memory
+        /// stays bounded.
+        ///
+        /// Assumes `crate::self_encryption` exposes `decode_data_map` and a
+        /// single-chunk `decrypt_chunk(index, bytes, &data_map)` — confirm
+        /// against that module if this doesn't build.
+        #[wasm_bindgen(js_name = fileDownloadStream)]
+        pub async fn file_download_stream(
+            &self,
+            addr: String,
+        ) -> Result<web_sys::ReadableStream, JsError> {
+            let addr = str_to_addr(&addr)?;
+            let data_map_chunk = self.0.chunk_get(addr).await?;
+            let data_map = decode_data_map(data_map_chunk.value())?;
+
+            let client = self.0.clone();
+            let source = futures::stream::iter(data_map.infos())
+                .then(move |info| {
+                    let client = client.clone();
+                    let data_map = data_map.clone();
+                    async move {
+                        let encrypted = client.chunk_get(info.dst_hash).await.map_err(|e| {
+                            JsError::new(&format!("failed to fetch chunk {}: {e}", info.index))
+                        })?;
+                        let plaintext = decrypt_chunk(info.index, encrypted.value(), &data_map)?;
+                        Ok::<_, JsError>(js_sys::Uint8Array::from(plaintext.as_ref()).into())
+                    }
+                })
+                .map_err(JsValue::from);
+
+            let stream = ReadableStream::from_stream(source);
+
+            Ok(stream.into_raw())
+        }
+    }
+
+    /// Bridge a JS error surfaced by the streams adapter back into a `JsError`.
+    fn js_to_error(value: JsValue) -> JsError {
+        JsError::new(
+            &value
+                .as_string()
+                .unwrap_or_else(|| "error reading from source stream".to_owned()),
+        )
+    }
+}
+
+/// Client-side end-to-end encryption of object payloads: a per-object key
+/// derived from the holder's BLS `SecretKey` via HKDF-SHA256 (salted with a
+/// fresh nonce), sealed with ChaCha20-Poly1305 as `version || nonce || ciphertext`.
+mod crypto {
+    use bls::SecretKey;
+    use chacha20poly1305::{
+        aead::{Aead, KeyInit},
+        ChaCha20Poly1305, Nonce,
+    };
+    use hkdf::Hkdf;
+    use sha2::Sha256;
+    use wasm_bindgen::JsError;
+
+    /// Current sealed-object format version.
+    const VERSION: u8 = 1;
+    /// ChaCha20-Poly1305 nonce length, in bytes.
+    const NONCE_LEN: usize = 12;
+    /// HKDF `info` string binding derived keys to this scheme.
+    const HKDF_INFO: &[u8] = b"autonomi-e2ee-v1";
+
+    /// Derive the per-object key from the secret key and the object's nonce.
+    fn derive_key(secret_key: &SecretKey, nonce: &[u8]) -> [u8; 32] {
+        let hk = Hkdf::<Sha256>::new(Some(nonce), &secret_key.to_bytes());
+        let mut key = [0u8; 32];
+        hk.expand(HKDF_INFO, &mut key)
+            .expect("32 is a valid HKDF-SHA256 output length");
+        key
+    }
+
+    /// Seal `plaintext` under `secret_key`, returning `version || nonce || ciphertext`.
+    pub fn seal(plaintext: &[u8], secret_key: &SecretKey) -> Result<Vec<u8>, JsError> {
+        let mut nonce_bytes = [0u8; NONCE_LEN];
+        getrandom::getrandom(&mut nonce_bytes)
+            .map_err(|e| JsError::new(&format!("failed to gather randomness: {e}")))?;
+
+        let key = derive_key(secret_key, &nonce_bytes);
+        let cipher = ChaCha20Poly1305::new((&key).into());
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce_bytes), plaintext)
+            .map_err(|_| JsError::new("failed to seal payload"))?;
+
+        let mut out = Vec::with_capacity(1 + NONCE_LEN + ciphertext.len());
+        out.push(VERSION);
+        out.extend_from_slice(&nonce_bytes);
+        out.extend_from_slice(&ciphertext);
+        Ok(out)
+    }
+
+    /// Open a payload previously produced by [`seal`].
+    pub fn open(sealed: &[u8], secret_key: &SecretKey) -> Result<Vec<u8>, JsError> {
+        if sealed.len() < 1 + NONCE_LEN {
+            return Err(JsError::new("sealed object is truncated"));
+        }
+        let version = sealed[0];
+        if version != VERSION {
+            return Err(JsError::new(&format!(
+                "unsupported sealed-object version {version}"
+            )));
+        }
+        let (nonce_bytes, ciphertext) = sealed[1..].split_at(NONCE_LEN);
+
+        let key = derive_key(secret_key, nonce_bytes);
+        let cipher = ChaCha20Poly1305::new((&key).into());
+        cipher
+            .decrypt(Nonce::from_slice(nonce_bytes), ciphertext)
+            .map_err(|_| JsError::new("failed to open payload (wrong key or corrupt data)"))
+    }
+}
+
 #[cfg(feature = "vault")]
 mod vault {
     use super::*;
@@ -119,6 +392,45 @@ mod vault {
 
     #[wasm_bindgen(js_class = Client)]
     impl JsClient {
+        /// Seal `data` with [`crypto`] under `secret_key` and store the
+        /// ciphertext through the ordinary [`Self::data_put`] path, so the
+        /// network only ever sees opaque bytes.
+        #[wasm_bindgen(js_name = encryptedDataPut)]
+        pub async fn encrypted_data_put(
+            &self,
+            data: Vec<u8>,
+            wallet: &JsWallet,
+            secret_key: Vec<u8>,
+        ) -> Result<String, JsError> {
+            let secret_key: [u8; 32] = secret_key[..].try_into()?;
+            let secret_key = SecretKey::from_bytes(secret_key)?;
+
+            let sealed = crypto::seal(&data, &secret_key)?;
+            let addr = self.0.data_put(crate::Bytes::from(sealed), &wallet.0).await?;
+
+            Ok(addr_to_str(addr))
+        }
+
+        /// Fetch and decrypt an object stored with [`Self::encrypted_data_put`].
+        #[wasm_bindgen(js_name = encryptedDataGet)]
+        pub async fn encrypted_data_get(
+            &self,
+            addr: String,
+            secret_key: Vec<u8>,
+        ) -> Result<Vec<u8>, JsError> {
+            let secret_key: [u8; 32] = secret_key[..].try_into()?;
+            let secret_key = SecretKey::from_bytes(secret_key)?;
+
+            let addr = str_to_addr(&addr)?;
+            let sealed = self.0.data_get(addr).await?;
+
+            crypto::open(&sealed, &secret_key)
+        }
+
+        /// Fetch the caller's vault `UserData`. Not sealed through [`crypto`]
+        /// like [`Self::encrypted_data_get`] — `get_user_data_from_vault`
+        /// already encrypts at the network layer under `secret_key`, and
+        /// `UserData` has no byte-container API to layer ciphertext through.
         #[wasm_bindgen(js_name = getUserDataFromVault)]
         pub async fn get_user_data_from_vault(
             &self,
@@ -132,6 +444,9 @@ mod vault {
             Ok(JsUserData(user_data))
         }
 
+        /// Store the caller's vault `UserData`; see
+        /// [`Self::get_user_data_from_vault`] for why this isn't also sealed
+        /// through [`crypto`].
         #[wasm_bindgen(js_name = putUserDataToVault)]
         pub async fn put_user_data_to_vault(
             &self,
@@ -151,6 +466,242 @@ mod vault {
     }
 }
 
+/// A long-lived browser session bundling a connected [`Client`](super::Client)
+/// with the caller's wallet and vault key, modelled on NextGraph's
+/// `LocalBroker`. It persists the last-known-good peer multiaddrs in
+/// `localStorage`, transparently re-dials them with exponential backoff when a
+/// request finds the connection dropped, and — once a vault key is registered —
+/// keeps the user's vault `UserData` in sync with every address it stores.
+#[wasm_bindgen(js_name = Session)]
+pub struct JsSession {
+    client: super::Client,
+    peers: Vec<Multiaddr>,
+    wallet: Option<evmlib::wallet::Wallet>,
+    #[cfg(feature = "vault")]
+    vault_key: Option<bls::SecretKey>,
+}
+
+/// Key under which the session caches its peer list in `localStorage`.
+const SESSION_PEERS_KEY: &str = "autonomi.session.peers";
+
+/// Maximum number of re-dial attempts before a reconnect gives up.
+const RECONNECT_MAX_RETRIES: u32 = 5;
+
+/// Base backoff, in milliseconds, doubled on each failed re-dial.
+const RECONNECT_BASE_BACKOFF_MS: u32 = 200;
+
+#[wasm_bindgen(js_class = Session)]
+impl JsSession {
+    /// Start a session, reusing cached peers from `localStorage` when the caller
+    /// does not supply any. The `config` object may carry `peers` (an array of
+    /// multiaddr strings); anything absent falls back to the cached value.
+    #[wasm_bindgen(js_name = sessionStart)]
+    pub async fn session_start(config: JsValue) -> Result<JsSession, JsError> {
+        let supplied: Vec<String> = if config.is_undefined() || config.is_null() {
+            Vec::new()
+        } else {
+            serde_wasm_bindgen::from_value::<SessionConfig>(config)?.peers
+        };
+
+        let peers = if supplied.is_empty() {
+            load_cached_peers()
+        } else {
+            supplied
+                .iter()
+                .map(|peer| peer.parse())
+                .collect::<Result<Vec<Multiaddr>, _>>()?
+        };
+
+        if peers.is_empty() {
+            return Err(JsError::new(
+                "no peers supplied and none cached from a previous session",
+            ));
+        }
+
+        let client = super::Client::connect(&peers).await?;
+        store_cached_peers(&peers);
+
+        Ok(JsSession {
+            client,
+            peers,
+            wallet: None,
+            #[cfg(feature = "vault")]
+            vault_key: None,
+        })
+    }
+
+    /// Drop the underlying client connection. The cached peers are retained so a
+    /// later [`Self::session_start`] can pick up where this one left off.
+    #[wasm_bindgen(js_name = sessionStop)]
+    pub fn session_stop(self) {
+        drop(self.client);
+    }
+
+    /// Register the wallet used to pay for uploads in this session.
+    #[wasm_bindgen(js_name = setWallet)]
+    pub fn set_wallet(&mut self, wallet: &JsWallet) {
+        self.wallet = Some(wallet.0.clone());
+    }
+
+    /// Register the vault secret key. Once set, [`Self::data_put`] and
+    /// [`Self::archive_put`] append the addresses they store to the user's vault.
+    #[cfg(feature = "vault")]
+    #[wasm_bindgen(js_name = setVaultKey)]
+    pub fn set_vault_key(&mut self, secret_key: Vec<u8>) -> Result<(), JsError> {
+        let secret_key: [u8; 32] = secret_key[..].try_into()?;
+        self.vault_key = Some(bls::SecretKey::from_bytes(secret_key)?);
+        Ok(())
+    }
+
+    /// Store `data`, re-dialing the cached peers first if the connection dropped,
+    /// and append the new address to the vault when a key is registered. Pays
+    /// with the wallet registered via [`Self::set_wallet`].
+    #[wasm_bindgen(js_name = dataPut)]
+    pub async fn data_put(&mut self, data: Vec<u8>) -> Result<String, JsError> {
+        self.ensure_connected().await?;
+        let wallet = self.registered_wallet()?;
+        let addr = self.client.data_put(crate::Bytes::from(data), &wallet).await?;
+        self.record_data_address(addr).await?;
+        Ok(addr_to_str(addr))
+    }
+
+    /// Store an archive built from a JS path→`(XorName, Metadata)` map, with the
+    /// same reconnect, wallet and vault-sync behaviour as [`Self::data_put`].
+    #[wasm_bindgen(js_name = archivePut)]
+    pub async fn archive_put(&mut self, map: JsValue) -> Result<String, JsError> {
+        use crate::client::archive::{Archive, Metadata};
+        use std::{collections::HashMap, path::PathBuf};
+        use xor_name::XorName;
+
+        self.ensure_connected().await?;
+        let wallet = self.registered_wallet()?;
+
+        let map: HashMap<PathBuf, (XorName, Metadata)> = serde_wasm_bindgen::from_value(map)?;
+        let mut archive = Archive::new();
+        for (path, (xorname, meta)) in map {
+            archive.add_file(path, xorname, meta);
+        }
+
+        let addr = self.client.archive_put(archive, &wallet).await?;
+        self.record_data_address(addr).await?;
+        Ok(addr_to_str(addr))
+    }
+
+    /// The wallet registered via [`Self::set_wallet`], or an error if none has
+    /// been set yet.
+    fn registered_wallet(&self) -> Result<evmlib::wallet::Wallet, JsError> {
+        self.wallet
+            .clone()
+            .ok_or_else(|| JsError::new("no wallet registered; call setWallet first"))
+    }
+
+    /// Re-dial the cached peers with exponential backoff. Returns early once a
+    /// connection is (re)established, erroring only after exhausting retries.
+    /// Assumes `Client::is_connected` is public.
+    async fn ensure_connected(&mut self) -> Result<(), JsError> {
+        if self.client.is_connected() {
+            return Ok(());
+        }
+
+        let mut backoff = RECONNECT_BASE_BACKOFF_MS;
+        for attempt in 0..RECONNECT_MAX_RETRIES {
+            match super::Client::connect(&self.peers).await {
+                Ok(client) => {
+                    self.client = client;
+                    store_cached_peers(&self.peers);
+                    return Ok(());
+                }
+                Err(err) => {
+                    tracing::warn!("session re-dial attempt {} failed: {err}", attempt + 1);
+                    sleep_ms(backoff).await;
+                    backoff = backoff.saturating_mul(2);
+                }
+            }
+        }
+
+        Err(JsError::new("failed to re-dial cached peers"))
+    }
+
+    /// Append a stored address to the vault `UserData`, if a vault key is set.
+    #[cfg(feature = "vault")]
+    async fn record_data_address(&mut self, addr: xor_name::XorName) -> Result<(), JsError> {
+        let Some(secret_key) = self.vault_key.clone() else {
+            return Ok(());
+        };
+        let Some(wallet) = self.wallet.clone() else {
+            return Ok(());
+        };
+
+        let mut user_data = self
+            .client
+            .get_user_data_from_vault(&secret_key)
+            .await
+            .unwrap_or_default();
+        user_data.add_file_archive(addr);
+        self.client
+            .put_user_data_to_vault(&secret_key, &wallet, user_data)
+            .await?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "vault"))]
+    async fn record_data_address(&mut self, _addr: xor_name::XorName) -> Result<(), JsError> {
+        Ok(())
+    }
+}
+
+#[derive(serde::Deserialize, Default)]
+struct SessionConfig {
+    #[serde(default)]
+    peers: Vec<String>,
+}
+
+/// Load the cached peer multiaddrs from `localStorage`, ignoring any that no
+/// longer parse. Returns an empty vec when nothing is cached or storage is
+/// unavailable (e.g. a private-browsing context).
+fn load_cached_peers() -> Vec<Multiaddr> {
+    let Some(storage) = local_storage() else {
+        return Vec::new();
+    };
+    let Ok(Some(raw)) = storage.get_item(SESSION_PEERS_KEY) else {
+        return Vec::new();
+    };
+    raw.split('\n').filter_map(|line| line.parse().ok()).collect()
+}
+
+/// Persist the peer multiaddrs to `localStorage`, silently skipping when storage
+/// is unavailable.
+fn store_cached_peers(peers: &[Multiaddr]) {
+    let Some(storage) = local_storage() else {
+        return;
+    };
+    let serialized = peers
+        .iter()
+        .map(|peer| peer.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let _ = storage.set_item(SESSION_PEERS_KEY, &serialized);
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// Resolve after `ms` milliseconds using the browser's timer, so reconnect
+/// backoff does not block the event loop. Resolves immediately when there is
+/// no `window` to schedule the timer on.
+async fn sleep_ms(ms: u32) {
+    let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+        if let Some(window) = web_sys::window() {
+            let _ = window
+                .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, ms as i32);
+        } else {
+            let _ = resolve.call0(&JsValue::NULL);
+        }
+    });
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
 #[wasm_bindgen(js_name = Wallet)]
 pub struct JsWallet(evmlib::wallet::Wallet);
 
@@ -12,14 +12,20 @@ use crate::{
     action::Action,
     components::{
         beta_programme::BetaProgramme, footer::Footer, help::HelpPopUp, home::Home,
-        resource_allocation::ResourceAllocationInputBox, Component,
+        network_status::NetworkStatus, resource_allocation::ResourceAllocationInputBox,
+        verify::Verify, Component,
     },
     config::{AppData, Config},
     mode::{InputMode, Scene},
     tui,
 };
 use color_eyre::eyre::Result;
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::{
+    cursor,
+    terminal::{disable_raw_mode, LeaveAlternateScreen},
+    ExecutableCommand,
+};
 use ratatui::prelude::Rect;
 use sn_peers_acquisition::PeersArgs;
 use tokio::sync::mpsc;
@@ -57,6 +63,8 @@ impl App {
         let resource_allocation_input =
             ResourceAllocationInputBox::new(app_data.allocated_disk_space)?;
         let footer = Footer::default();
+        let network_status = NetworkStatus::new();
+        let verify = Verify::new(app_data.discord_username.clone());
         let help = HelpPopUp::default();
 
         Ok(Self {
@@ -66,9 +74,11 @@ impl App {
             frame_rate,
             components: vec![
                 Box::new(footer),
+                Box::new(network_status),
                 Box::new(home),
                 Box::new(discord_username_input),
                 Box::new(resource_allocation_input),
+                Box::new(verify),
                 Box::new(help),
             ],
             should_quit: false,
@@ -82,6 +92,11 @@ impl App {
     pub async fn run(&mut self) -> Result<()> {
         let (action_tx, mut action_rx) = mpsc::unbounded_channel();
 
+        // Install the terminal-restoring panic hook before entering raw mode so
+        // an unwind from any component's `draw`/`update` still leaves the user
+        // with a usable terminal.
+        install_panic_hook()?;
+
         let mut tui = tui::Tui::new()?
             .tick_rate(self.tick_rate)
             .frame_rate(self.frame_rate);
@@ -102,7 +117,18 @@ impl App {
                     tui::Event::Render => action_tx.send(Action::Render)?,
                     tui::Event::Resize(x, y) => action_tx.send(Action::Resize(x, y))?,
                     tui::Event::Key(key) => {
-                        if self.input_mode == InputMode::Navigation {
+                        if self.scene == Scene::Verify {
+                            // Gate on an explicit confirmation rather than the normal
+                            // keymap, since this scene's whole point is to make the
+                            // user look at the fingerprint before anything is saved.
+                            match key.code {
+                                KeyCode::Enter => action_tx.send(Action::ConfirmIdentity)?,
+                                KeyCode::Esc => {
+                                    action_tx.send(Action::SwitchScene(Scene::Home))?
+                                }
+                                _ => {}
+                            }
+                        } else if self.input_mode == InputMode::Navigation {
                             if let Some(keymap) = self.config.keybindings.get(&self.scene) {
                                 if let Some(action) = keymap.get(&vec![key]) {
                                     info!("Got action: {action:?}");
@@ -179,12 +205,19 @@ impl App {
                     Action::StoreDiscordUserName(ref username) => {
                         debug!("Storing discord username: {username:?}");
                         self.app_data.discord_username.clone_from(username);
-                        self.app_data.save()?;
+                        // Hold off on persisting until the user confirms the
+                        // identity fingerprint shown by `Verify`.
+                        action_tx.send(Action::SwitchScene(Scene::Verify))?;
                     }
                     Action::StoreAllocatedDiskSpace(space) => {
                         debug!("Storing allocated disk space: {space:?}");
                         self.app_data.allocated_disk_space = space;
+                        action_tx.send(Action::SwitchScene(Scene::Verify))?;
+                    }
+                    Action::ConfirmIdentity => {
+                        debug!("Identity confirmed, persisting app data");
                         self.app_data.save()?;
+                        action_tx.send(Action::SwitchScene(Scene::Home))?;
                     }
                     _ => {}
                 }
@@ -197,6 +230,8 @@ impl App {
             if self.should_suspend {
                 tui.suspend()?;
                 action_tx.send(Action::Resume)?;
+                // Re-arm the panic hook for the freshly re-entered terminal.
+                install_panic_hook()?;
                 tui = tui::Tui::new()?
                     .tick_rate(self.tick_rate)
                     .frame_rate(self.frame_rate);
@@ -211,3 +246,41 @@ impl App {
         Ok(())
     }
 }
+
+/// Install the terminal-restoring panic hook, installing `color_eyre`'s report
+/// hook the first time this is called.
+///
+/// `eyre_hook.install()` sets a process-global hook and errors if one is
+/// already set, so it only runs once (guarded by `EYRE_HOOK_INSTALLED`); the
+/// `std::panic` hook that actually restores the terminal — leaving the
+/// alternate screen, disabling raw mode, showing the cursor — has no such
+/// restriction and is safe to re-arm on every call, which is what lets the
+/// suspend/resume path call this again against the freshly entered terminal.
+fn install_panic_hook() -> Result<()> {
+    static EYRE_HOOK_INSTALLED: std::sync::Once = std::sync::Once::new();
+
+    let (panic_hook, eyre_hook) = color_eyre::config::HookBuilder::default().into_hooks();
+
+    let mut install_err = None;
+    EYRE_HOOK_INSTALLED.call_once(|| {
+        if let Err(err) = eyre_hook.install() {
+            install_err = Some(err);
+        }
+    });
+    if let Some(err) = install_err {
+        return Err(err);
+    }
+
+    std::panic::set_hook(Box::new(move |info| {
+        // Best-effort terminal restore; ignore errors since we are already
+        // unwinding and about to print the panic regardless.
+        let mut stderr = std::io::stderr();
+        let _ = stderr.execute(LeaveAlternateScreen);
+        let _ = stderr.execute(cursor::Show);
+        let _ = disable_raw_mode();
+
+        eprintln!("{}", panic_hook.panic_report(info));
+    }));
+
+    Ok(())
+}
@@ -0,0 +1,163 @@
+// Copyright 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use super::Component;
+use crate::{action::Action, config::Config};
+use color_eyre::eyre::Result;
+use ratatui::{prelude::*, widgets::Paragraph};
+use sn_node_rpc_client::RpcClient;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// A compact, one-line summary of the running node's runtime metrics, refreshed
+/// on a timer from the `Action::Tick` loop and drawn beside the `Footer`.
+///
+/// The component itself holds no network handle: on each tick (throttled to the
+/// configured refresh interval) it kicks off an async poll that emits
+/// `Action::UpdateNodeStats`, which flows back through the same `action_tx` /
+/// `component.update` path as every other action.
+#[derive(Default)]
+pub struct NetworkStatus {
+    stats: Option<NodeStats>,
+    command_tx: Option<UnboundedSender<Action>>,
+    refresh_interval: Duration,
+    last_refresh: Option<Instant>,
+    rpc_endpoint: Option<SocketAddr>,
+}
+
+/// Runtime metrics scraped from a running `safenode`.
+#[derive(Clone, Debug, Default)]
+pub struct NodeStats {
+    pub peers_connected: usize,
+    pub records_stored: usize,
+    pub memory_used_mb: u64,
+    pub rewards: String,
+}
+
+/// Default refresh cadence when `Config` does not override it.
+const DEFAULT_REFRESH_SECS: u64 = 5;
+
+/// Default RPC address a locally spawned `safenode` listens on, used when
+/// `Config` does not override it.
+const DEFAULT_RPC_ENDPOINT: &str = "127.0.0.1:12001";
+
+impl NetworkStatus {
+    pub fn new() -> Self {
+        Self {
+            refresh_interval: Duration::from_secs(DEFAULT_REFRESH_SECS),
+            rpc_endpoint: DEFAULT_RPC_ENDPOINT.parse().ok(),
+            ..Default::default()
+        }
+    }
+
+    /// Spawn an out-of-band poll of the running node, emitting the result as
+    /// `Action::UpdateNodeStats` so it re-enters the normal action loop.
+    fn schedule_poll(&mut self) {
+        let Some(tx) = self.command_tx.clone() else {
+            return;
+        };
+        let Some(rpc_endpoint) = self.rpc_endpoint else {
+            return;
+        };
+
+        // Throttle to the configured interval so a fast tick rate does not
+        // hammer the node's metrics endpoint.
+        let now = Instant::now();
+        if let Some(last) = self.last_refresh {
+            if now.duration_since(last) < self.refresh_interval {
+                return;
+            }
+        }
+        self.last_refresh = Some(now);
+
+        tokio::spawn(async move {
+            match NodeStats::collect(rpc_endpoint).await {
+                Ok(stats) => {
+                    let _ = tx.send(Action::UpdateNodeStats(stats));
+                }
+                Err(err) => {
+                    debug!("Failed to collect node stats: {err:?}");
+                }
+            }
+        });
+    }
+}
+
+impl Component for NetworkStatus {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        if let Some(secs) = config.app.network_status_refresh_interval {
+            self.refresh_interval = Duration::from_secs(secs);
+        }
+        if let Some(ref addr) = config.app.node_rpc_addr {
+            self.rpc_endpoint = addr.parse().ok();
+        }
+        Ok(())
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::Tick => self.schedule_poll(),
+            Action::UpdateNodeStats(stats) => self.stats = Some(stats),
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let line = match &self.stats {
+            Some(stats) => format!(
+                " peers {} · records {} · mem {} MB · rewards {} ",
+                stats.peers_connected, stats.records_stored, stats.memory_used_mb, stats.rewards
+            ),
+            None => " collecting node stats… ".to_string(),
+        };
+
+        // Carve out the footer row, then its right-hand portion, leaving the
+        // existing `Footer` to own the left-hand side of that same row.
+        let footer_row = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(area)[1];
+        let status_area = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+            .split(footer_row)[1];
+
+        let status = Paragraph::new(line)
+            .style(Style::default().fg(Color::Gray))
+            .alignment(Alignment::Right);
+        f.render_widget(status, status_area);
+        Ok(())
+    }
+}
+
+impl NodeStats {
+    /// Query the running `safenode` at `rpc_endpoint` for its current metrics.
+    ///
+    /// Mirrors the informant pattern: a cheap point-in-time scrape that is
+    /// cheap enough to run on every refresh tick.
+    async fn collect(rpc_endpoint: SocketAddr) -> Result<Self> {
+        let rpc_client = RpcClient::new(&format!("https://{rpc_endpoint}"));
+
+        let node_info = rpc_client.node_info().await?;
+        let network_info = rpc_client.network_info().await?;
+
+        Ok(Self {
+            peers_connected: network_info.connected_peers.len(),
+            records_stored: network_info.records.len(),
+            memory_used_mb: node_info.mem_used_mb,
+            rewards: node_info.wallet_balance.to_string(),
+        })
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use super::Component;
+use crate::{
+    action::Action,
+    mode::Scene,
+};
+use color_eyre::eyre::Result;
+use ratatui::{prelude::*, widgets::Paragraph};
+
+/// A short, deterministic emoji fingerprint of the operator's identity, shown
+/// so the user can visually confirm they restored the right Discord username
+/// before it is persisted — the same trick NextGraph uses for wallet recovery.
+/// The fingerprint is derived from the Discord username alone; no wallet key
+/// is part of it.
+#[derive(Default)]
+pub struct Verify {
+    active: bool,
+    identity: String,
+}
+
+/// Curated emoji alphabet. Each 4-bit nibble of the identity hash indexes into
+/// it, so it must hold exactly 16 entries for the nibble indexing in
+/// [`fingerprint`] to stay in bounds and use every entry.
+const EMOJI_ALPHABET: [&str; 16] = [
+    "🐶", "🐱", "🦊", "🐻", "🐼", "🦁", "🐸", "🐵", "🦉", "🐢", "🐳", "🦋", "🌵", "🍄", "⭐", "🔑",
+];
+
+/// Number of hash bytes consumed to build a fingerprint. Each byte contributes
+/// two emojis (its high nibble, then its low nibble), so a fingerprint is
+/// `FINGERPRINT_BYTES * 2` emojis wide and carries `FINGERPRINT_BYTES * 8`
+/// bits of entropy — consuming only one nibble per byte halved that and left
+/// identities colliding far too often to be a meaningful check.
+const FINGERPRINT_BYTES: usize = 4;
+
+impl Verify {
+    pub fn new(identity: String) -> Self {
+        Self {
+            active: false,
+            identity,
+        }
+    }
+}
+
+impl Component for Verify {
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::SwitchScene(Scene::Verify) => self.active = true,
+            Action::SwitchScene(_) => self.active = false,
+            // Keep the rendered fingerprint in step with any identity the user
+            // types in before it is stored.
+            Action::StoreDiscordUserName(ref username) => self.identity.clone_from(username),
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        if !self.active {
+            return Ok(());
+        }
+
+        let text = format!(
+            "Identity fingerprint: {}\nConfirm this matches the one from your other machines.",
+            fingerprint(self.identity.as_bytes())
+        );
+        f.render_widget(Paragraph::new(text).alignment(Alignment::Center), area);
+        Ok(())
+    }
+}
+
+/// Map the first bytes of the BLAKE3 hash of `identity` into a fixed-length
+/// emoji sequence. Deterministic across machines for the same identity bytes.
+fn fingerprint(identity: &[u8]) -> String {
+    let hash = blake3::hash(identity);
+    hash.as_bytes()
+        .iter()
+        .take(FINGERPRINT_BYTES)
+        .flat_map(|byte| {
+            [
+                EMOJI_ALPHABET[(byte >> 4) as usize],
+                EMOJI_ALPHABET[(byte & 0x0f) as usize],
+            ]
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}